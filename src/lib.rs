@@ -41,7 +41,7 @@ impl MPFloatingPointer {
         /* The [MPSpec] says (Table 4-1.) that the checksum is valid if
          * all the bytes add up to zero.
          */
-        return (checksum & 0x0f) == 0
+        (checksum & 0xff) == 0
     }
 
     pub fn verify_signature(&self) -> bool {
@@ -53,6 +53,124 @@ impl MPFloatingPointer {
     pub fn is_valid(&self) -> bool {
         self.verify_checksum() && self.verify_signature()
     }
+
+    /// Decode the platform configuration selected by the feature info bytes.
+    ///
+    /// Per [MPSpec] Section 4.1 a non-zero feature byte 1 means no
+    /// configuration table is present and the platform matches one of the
+    /// seven predefined default configurations; a zero byte means the
+    /// `physical_address_pointer` points at a PCMP table.
+    pub fn configuration(&self) -> MPConfiguration {
+        match self.mp_feature_info_bytes[0] {
+            0 => MPConfiguration::TablePresent,
+            n => match DefaultBusLayout::from_configuration(n) {
+                Some(buses) => MPConfiguration::Default(DefaultConfiguration { number: n, buses }),
+                None => MPConfiguration::Reserved(n),
+            },
+        }
+    }
+
+    /// Whether the IMCR is present and PIC mode is implemented.
+    ///
+    /// This is bit 7 (IMCRP) of feature byte 2; when set, the operating system
+    /// must switch the IMCR to symmetric I/O mode before using the IOAPICs.
+    pub fn imcr_present(&self) -> bool {
+        self.mp_feature_info_bytes[1] & 0x80 != 0
+    }
+
+    /// Read an `MPFloatingPointer` out of a 16-byte (or longer) buffer.
+    ///
+    /// Returns `None` when the slice is too short to hold the fixed part of
+    /// the structure.
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        if buf.len() < 16 {
+            return None
+        }
+
+        let mut mp_feature_info_bytes = [0; 5];
+        mp_feature_info_bytes.copy_from_slice(&buf[11..16]);
+
+        Some(MPFloatingPointer {
+            signature: LittleEndian::read_u32(&buf[0..4]),
+            physical_address_pointer: LittleEndian::read_u32(&buf[4..8]),
+            length: buf[8],
+            spec_rev: buf[9],
+            checksum: buf[10],
+            mp_feature_info_bytes,
+        })
+    }
+}
+
+/// The bus layout implied by a predefined default configuration
+/// ([MPSpec] Table 5-1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefaultBusLayout {
+    Isa,
+    Eisa,
+    Mca,
+    IsaPci,
+    EisaPci,
+    McaPci,
+}
+
+impl DefaultBusLayout {
+    /// The bus layout for default configuration `number`, or `None` when the
+    /// number is outside the spec's valid range of 1 through 7.
+    fn from_configuration(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(DefaultBusLayout::Isa),
+            2 | 3 => Some(DefaultBusLayout::Eisa),
+            4 => Some(DefaultBusLayout::Mca),
+            5 => Some(DefaultBusLayout::IsaPci),
+            6 => Some(DefaultBusLayout::EisaPci),
+            7 => Some(DefaultBusLayout::McaPci),
+            _ => None,
+        }
+    }
+}
+
+/// One of the seven predefined default configurations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DefaultConfiguration {
+    /// The configuration number, 1 through 7.
+    pub number: u8,
+    /// The buses present in this configuration.
+    pub buses: DefaultBusLayout,
+}
+
+/// Whether a machine ships a configuration table or a predefined default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MPConfiguration {
+    /// A PCMP table is present at the floating pointer's physical address.
+    TablePresent,
+    /// No table is present; the platform matches this default configuration.
+    Default(DefaultConfiguration),
+    /// Feature byte 1 held a value outside the valid 1-7 default range.
+    Reserved(u8),
+}
+
+/// Walk a set of memory regions looking for the MP Floating Pointer Structure.
+///
+/// Per [MPSpec] Section 4 the structure lives on a 16-byte boundary in one of
+/// three places: the first kilobyte of the Extended BIOS Data Area, the last
+/// kilobyte of base conventional memory, and the BIOS read-only region
+/// 0xF0000-0xFFFFF. The caller passes those regions as byte slices; each is
+/// scanned on 16-byte-aligned boundaries and the first candidate whose
+/// signature and checksum both verify is returned along with its offset into
+/// the region it was found in.
+pub fn find_floating_pointer(regions: &[&[u8]]) -> Option<(usize, MPFloatingPointer)> {
+    for region in regions {
+        let mut offset = 0;
+        while offset + 16 <= region.len() {
+            if let Some(fp) = MPFloatingPointer::from_bytes(&region[offset..]) {
+                if fp.verify_signature() && fp.verify_checksum() {
+                    return Some((offset, fp))
+                }
+            }
+            offset += 16;
+        }
+    }
+    None
 }
 
 #[repr(C, packed)]
@@ -89,16 +207,66 @@ impl MPConfigurationTableHeader {
         self.verify_checksum() && self.verify_signature()
     }
 
-    pub fn iter(&self, table_location: usize) -> EntryIterator {
+    /// Iterate the base-table entries stored in `table`.
+    ///
+    /// `table` must be the configuration table as it lives in memory, i.e.
+    /// starting at the header; the 44-byte header is skipped internally.
+    pub fn iter<'a>(&self, table: &'a [u8]) -> EntryIterator<'a> {
         EntryIterator {
-            table_location: table_location + 44,
+            table,
             total_entries: self.entry_count as usize,
             entries_sofar: 0,
-            current_offset: 0,
+            current_offset: 44,
+            done: false,
+        }
+    }
+
+    /// Iterate the base-table entries living at a raw physical address.
+    ///
+    /// This reconstructs a slice spanning the header and `base_table_length`
+    /// bytes and defers to [`iter`](Self::iter), so the unsafe read happens in
+    /// exactly one place.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `table_location` points at a mapped
+    /// configuration table of at least `base_table_length` bytes that stays
+    /// alive for the lifetime of the returned iterator.
+    pub unsafe fn iter_raw(&self, table_location: usize) -> EntryIterator<'static> {
+        let table = core::slice::from_raw_parts(
+            table_location as *const u8,
+            self.base_table_length as usize,
+        );
+        self.iter(table)
+    }
+
+    /// Iterate the extended-table entries stored in `table`.
+    ///
+    /// `table` must be the whole configuration table starting at the header.
+    /// The extended section immediately follows the base table, so it starts
+    /// at `base_table_length` and runs for `extended_table_length` bytes.
+    pub fn iter_extended<'a>(&self, table: &'a [u8]) -> ExtendedEntryIterator<'a> {
+        let start = self.base_table_length as usize;
+        ExtendedEntryIterator {
+            table,
+            current_offset: start,
+            end_offset: start + self.extended_table_length as usize,
+            done: false,
         }
     }
 }
 
+/// Errors produced while parsing a configuration table out of a byte slice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The slice ended before the declared entry (or its header) was complete.
+    TruncatedTable,
+    /// The entry type byte did not match any entry code from the spec.
+    UnknownEntryCode(u8),
+    /// A cursor or offset landed outside of the table.
+    BadOffset,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MPEntryCode {
     Processor = 0,
@@ -136,27 +304,44 @@ impl MPEntryCode {
 }
 
 #[derive(Debug)]
-pub struct EntryIterator {
-    table_location: usize,
+pub struct EntryIterator<'a> {
+    table: &'a [u8],
     entries_sofar: usize,
     total_entries: usize,
     current_offset: usize,
+    done: bool,
 }
 
-impl Iterator for EntryIterator {
-    type Item = MPEntryCode;
+impl<'a> Iterator for EntryIterator<'a> {
+    type Item = Result<MPEntry, ParseError>;
 
-    fn next(&mut self) -> Option<MPEntryCode> {
-        if self.entries_sofar >= self.total_entries {
-            None
-        } else {
-            let current_addr = self.table_location + self.current_offset;
-            let current_ptr = current_addr as *const u8;
-            let current_code: MPEntryCode = unsafe { MPEntryCode::from_u8(*current_ptr) };
-            self.entries_sofar += 1;
-            self.current_offset += current_code.length();
-            Some(current_code)
+    fn next(&mut self) -> Option<Result<MPEntry, ParseError>> {
+        if self.done || self.entries_sofar >= self.total_entries {
+            return None
         }
+
+        /* The entry type byte must itself be inside the table. */
+        let rest = match self.table.get(self.current_offset..) {
+            Some(rest) => rest,
+            None => {
+                self.done = true;
+                return Some(Err(ParseError::BadOffset))
+            }
+        };
+
+        let entry = match MPEntry::from_bytes(rest) {
+            Ok(entry) => entry,
+            Err(e) => {
+                /* A malformed entry fuses the iterator: there is no way to
+                 * know where the next entry begins, so stop the scan. */
+                self.done = true;
+                return Some(Err(e))
+            }
+        };
+
+        self.entries_sofar += 1;
+        self.current_offset += entry.code.length();
+        Some(Ok(entry))
     }
 }
 
@@ -174,6 +359,40 @@ pub struct MPEntry {
 }
 
 impl MPEntry {
+    /// Parse a single base-table entry out of the front of `buf`.
+    ///
+    /// `buf` must start at the entry's type byte. The declared length for the
+    /// decoded code is bounds-checked against `buf` before any bytes are read,
+    /// so this never reads out of the slice.
+    pub fn from_bytes(buf: &[u8]) -> Result<MPEntry, ParseError> {
+        let code_byte = *buf.first().ok_or(ParseError::TruncatedTable)?;
+        let code = MPEntryCode::from_u8(code_byte);
+        if code == MPEntryCode::Unknown {
+            return Err(ParseError::UnknownEntryCode(code_byte))
+        }
+
+        let len = code.length();
+        if buf.len() < len {
+            return Err(ParseError::TruncatedTable)
+        }
+
+        let entries = match code {
+            MPEntryCode::Processor =>
+                MPPossibleEntries { processor: ProcessorEntry::from_bytes(buf) },
+            MPEntryCode::Bus =>
+                MPPossibleEntries { bus: BusEntry::from_bytes(buf) },
+            MPEntryCode::IOAPIC =>
+                MPPossibleEntries { ioapic: IOAPICEntry::from_bytes(buf) },
+            MPEntryCode::IOInterruptAssignment =>
+                MPPossibleEntries { io_interrupt_assignment: IOInterruptAssignmentEntry::from_bytes(buf) },
+            MPEntryCode::LocalInterruptAssignment =>
+                MPPossibleEntries { local_interrupt_assignment: LocalInterruptAssignmentEntry::from_bytes(buf) },
+            MPEntryCode::Unknown => unreachable!(),
+        };
+
+        Ok(MPEntry { code, entries })
+    }
+
     pub fn get_processor_entry(&self) -> Option<ProcessorEntry> {
         if self.code == MPEntryCode::Processor {
             Some(unsafe { self.entries.processor })
@@ -227,6 +446,24 @@ pub struct ProcessorEntry {
     pub feature_flags: u32,
 }
 
+impl ProcessorEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        let mut cpu_signature = [0; 2];
+        cpu_signature.copy_from_slice(&buf[4..6]);
+        let mut unused = [0; 2];
+        unused.copy_from_slice(&buf[6..8]);
+        ProcessorEntry {
+            entry_type: buf[0],
+            lapic_id: buf[1],
+            lapic_version: buf[2],
+            cpu_flags: buf[3],
+            cpu_signature,
+            unused,
+            feature_flags: LittleEndian::read_u32(&buf[8..12]),
+        }
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug)]
 pub struct BusEntry {
@@ -235,6 +472,63 @@ pub struct BusEntry {
     pub bus_type_string: [u8; 6],
 }
 
+impl BusEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        let mut bus_type_string = [0; 6];
+        bus_type_string.copy_from_slice(&buf[2..8]);
+        BusEntry {
+            entry_type: buf[0],
+            bus_id: buf[1],
+            bus_type_string,
+        }
+    }
+
+    /// Decode the six-character, space-padded bus type string into a known bus.
+    pub fn bus_type(&self) -> BusType {
+        BusType::from_bytes(&self.bus_type_string)
+    }
+}
+
+/// A bus type as spelled in a [`BusEntry`]'s `bus_type_string`
+/// ([MPSpec] Table 4-8).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BusType {
+    Isa,
+    Eisa,
+    Pci,
+    Mca,
+    Pcmcia,
+    VesaLocal,
+    Unknown,
+}
+
+impl BusType {
+    fn from_bytes(bytes: &[u8; 6]) -> Self {
+        match bytes {
+            b"ISA   " => BusType::Isa,
+            b"EISA  " => BusType::Eisa,
+            b"PCI   " => BusType::Pci,
+            b"MCA   " => BusType::Mca,
+            b"PCMCIA" => BusType::Pcmcia,
+            b"VL    " => BusType::VesaLocal,
+            _ => BusType::Unknown,
+        }
+    }
+
+    /// The canonical short name for this bus type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BusType::Isa => "ISA",
+            BusType::Eisa => "EISA",
+            BusType::Pci => "PCI",
+            BusType::Mca => "MCA",
+            BusType::Pcmcia => "PCMCIA",
+            BusType::VesaLocal => "VL",
+            BusType::Unknown => "Unknown",
+        }
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug)]
 pub struct IOAPICEntry {
@@ -245,28 +539,516 @@ pub struct IOAPICEntry {
     pub ioapic_address: u32,
 }
 
+impl IOAPICEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        IOAPICEntry {
+            entry_type: buf[0],
+            ioapic_id: buf[1],
+            ioapic_version: buf[2],
+            ioapic_flags: buf[3],
+            ioapic_address: LittleEndian::read_u32(&buf[4..8]),
+        }
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug)]
 pub struct IOInterruptAssignmentEntry {
     pub entry_type: u8,
     pub interrupt_type: u8,
-    pub interrupt_mode: u8,
-    unused: u8,
+    /// Polarity and trigger-mode flags, a single 16-bit word ([MPSpec] 4.3.4).
+    pub flags: u16,
     pub source_bus_id: u8,
     pub source_bus_irq: u8,
     pub dest_ioapic_id: u8,
     pub dest_ioapic_int: u8,
 }
 
+impl IOInterruptAssignmentEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        IOInterruptAssignmentEntry {
+            entry_type: buf[0],
+            interrupt_type: buf[1],
+            flags: LittleEndian::read_u16(&buf[2..4]),
+            source_bus_id: buf[4],
+            source_bus_irq: buf[5],
+            dest_ioapic_id: buf[6],
+            dest_ioapic_int: buf[7],
+        }
+    }
+
+    /// The decoded interrupt type (INT/NMI/SMI/ExtINT).
+    pub fn interrupt_type(&self) -> InterruptType {
+        InterruptType::from_u8(self.interrupt_type)
+    }
+
+    /// The decoded signal polarity.
+    pub fn polarity(&self) -> Polarity {
+        Polarity::from_flags(self.flags)
+    }
+
+    /// The decoded trigger mode.
+    pub fn trigger_mode(&self) -> TriggerMode {
+        TriggerMode::from_flags(self.flags)
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy, Debug)]
 pub struct LocalInterruptAssignmentEntry {
     pub entry_type: u8,
     pub interrupt_type: u8,
-    pub interrupt_mode: u8,
-    unused: u8,
+    /// Polarity and trigger-mode flags, a single 16-bit word ([MPSpec] 4.3.4).
+    pub flags: u16,
     pub source_bus_id: u8,
     pub source_bus_irq: u8,
     pub dest_ioapic_id: u8,
     pub dest_ioapic_int: u8,
 }
+
+impl LocalInterruptAssignmentEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        LocalInterruptAssignmentEntry {
+            entry_type: buf[0],
+            interrupt_type: buf[1],
+            flags: LittleEndian::read_u16(&buf[2..4]),
+            source_bus_id: buf[4],
+            source_bus_irq: buf[5],
+            dest_ioapic_id: buf[6],
+            dest_ioapic_int: buf[7],
+        }
+    }
+
+    /// The decoded interrupt type (INT/NMI/SMI/ExtINT).
+    pub fn interrupt_type(&self) -> InterruptType {
+        InterruptType::from_u8(self.interrupt_type)
+    }
+
+    /// The decoded signal polarity.
+    pub fn polarity(&self) -> Polarity {
+        Polarity::from_flags(self.flags)
+    }
+
+    /// The decoded trigger mode.
+    pub fn trigger_mode(&self) -> TriggerMode {
+        TriggerMode::from_flags(self.flags)
+    }
+}
+
+/// The type codes used by the extended configuration table entries, as defined
+/// in [MPSpec] Section 4.3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MPExtendedEntryCode {
+    SystemAddressSpaceMapping = 128,
+    BusHierarchyDescriptor = 129,
+    CompatibilityBusAddressSpaceModifier = 130,
+    Unknown = 255,
+}
+
+impl MPExtendedEntryCode {
+    pub fn from_u8(num: u8) -> Self {
+        match num {
+            128 => MPExtendedEntryCode::SystemAddressSpaceMapping,
+            129 => MPExtendedEntryCode::BusHierarchyDescriptor,
+            130 => MPExtendedEntryCode::CompatibilityBusAddressSpaceModifier,
+            _ => MPExtendedEntryCode::Unknown,
+        }
+    }
+
+    /// The fixed size, in bytes, that an entry of this type must declare.
+    pub fn length(&self) -> usize {
+        match self {
+            MPExtendedEntryCode::SystemAddressSpaceMapping => 20,
+            MPExtendedEntryCode::BusHierarchyDescriptor => 8,
+            MPExtendedEntryCode::CompatibilityBusAddressSpaceModifier => 8,
+            MPExtendedEntryCode::Unknown =>
+                panic!("Trying to get length of unknown extended MP entry: {:?}", self),
+        }
+    }
+}
+
+/// System Address Space Mapping entry (type 128, [MPSpec] Section 4.3.1).
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct SystemAddressSpaceMappingEntry {
+    pub entry_type: u8,
+    pub entry_length: u8,
+    pub address_type: u8,
+    pub bus_id: u8,
+    pub address_base: u64,
+    pub address_length: u64,
+}
+
+impl SystemAddressSpaceMappingEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        SystemAddressSpaceMappingEntry {
+            entry_type: buf[0],
+            entry_length: buf[1],
+            address_type: buf[2],
+            bus_id: buf[3],
+            address_base: LittleEndian::read_u64(&buf[4..12]),
+            address_length: LittleEndian::read_u64(&buf[12..20]),
+        }
+    }
+}
+
+/// Bus Hierarchy Descriptor entry (type 129, [MPSpec] Section 4.3.2).
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct BusHierarchyDescriptorEntry {
+    pub entry_type: u8,
+    pub entry_length: u8,
+    pub bus_id: u8,
+    pub bus_info: u8,
+    pub parent_bus: u8,
+    reserved: [u8; 3],
+}
+
+impl BusHierarchyDescriptorEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        let mut reserved = [0; 3];
+        reserved.copy_from_slice(&buf[5..8]);
+        BusHierarchyDescriptorEntry {
+            entry_type: buf[0],
+            entry_length: buf[1],
+            bus_id: buf[2],
+            bus_info: buf[3],
+            parent_bus: buf[4],
+            reserved,
+        }
+    }
+}
+
+/// Compatibility Bus Address Space Modifier entry (type 130,
+/// [MPSpec] Section 4.3.3).
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct CompatibilityBusAddressSpaceModifierEntry {
+    pub entry_type: u8,
+    pub entry_length: u8,
+    pub bus_id: u8,
+    pub address_modifier: u8,
+    pub predefined_range_list: u32,
+}
+
+impl CompatibilityBusAddressSpaceModifierEntry {
+    fn from_bytes(buf: &[u8]) -> Self {
+        CompatibilityBusAddressSpaceModifierEntry {
+            entry_type: buf[0],
+            entry_length: buf[1],
+            bus_id: buf[2],
+            address_modifier: buf[3],
+            predefined_range_list: LittleEndian::read_u32(&buf[4..8]),
+        }
+    }
+}
+
+/// A decoded extended-table entry.
+#[derive(Clone, Copy, Debug)]
+pub enum MPExtendedEntry {
+    SystemAddressSpaceMapping(SystemAddressSpaceMappingEntry),
+    BusHierarchyDescriptor(BusHierarchyDescriptorEntry),
+    CompatibilityBusAddressSpaceModifier(CompatibilityBusAddressSpaceModifierEntry),
+}
+
+/// Iterates the variable-length entries of the extended configuration table.
+///
+/// Unlike the base entries, each extended entry carries its own length byte at
+/// offset 1, so the iterator advances by that per-entry length and stops once
+/// it reaches `extended_table_length`.
+#[derive(Debug)]
+pub struct ExtendedEntryIterator<'a> {
+    table: &'a [u8],
+    current_offset: usize,
+    end_offset: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for ExtendedEntryIterator<'a> {
+    type Item = Result<MPExtendedEntry, ParseError>;
+
+    fn next(&mut self) -> Option<Result<MPExtendedEntry, ParseError>> {
+        if self.done || self.current_offset >= self.end_offset {
+            return None
+        }
+
+        /* Both the type and the per-entry length byte must be present. The
+         * declared extended_table_length is not trusted, so every bound is
+         * checked against the actual slice as well as end_offset. */
+        let len = match self.table.get(self.current_offset + 1) {
+            Some(&len) => len as usize,
+            None => {
+                self.done = true;
+                return Some(Err(ParseError::BadOffset))
+            }
+        };
+
+        let code = MPExtendedEntryCode::from_u8(self.table[self.current_offset]);
+        if code == MPExtendedEntryCode::Unknown {
+            self.done = true;
+            return Some(Err(ParseError::UnknownEntryCode(self.table[self.current_offset])))
+        }
+
+        /* The declared length must match the type and fit in both the extended
+         * section and the backing slice; otherwise the scan cannot continue. */
+        if len != code.length()
+            || self.current_offset + len > self.end_offset
+            || self.current_offset + len > self.table.len()
+        {
+            self.done = true;
+            return Some(Err(ParseError::TruncatedTable))
+        }
+
+        let rest = &self.table[self.current_offset..self.current_offset + len];
+        let entry = match code {
+            MPExtendedEntryCode::SystemAddressSpaceMapping =>
+                MPExtendedEntry::SystemAddressSpaceMapping(
+                    SystemAddressSpaceMappingEntry::from_bytes(rest)),
+            MPExtendedEntryCode::BusHierarchyDescriptor =>
+                MPExtendedEntry::BusHierarchyDescriptor(
+                    BusHierarchyDescriptorEntry::from_bytes(rest)),
+            MPExtendedEntryCode::CompatibilityBusAddressSpaceModifier =>
+                MPExtendedEntry::CompatibilityBusAddressSpaceModifier(
+                    CompatibilityBusAddressSpaceModifierEntry::from_bytes(rest)),
+            MPExtendedEntryCode::Unknown => unreachable!(),
+        };
+
+        self.current_offset += len;
+        Some(Ok(entry))
+    }
+}
+
+/// The kind of interrupt delivered by an interrupt-assignment entry
+/// ([MPSpec] Section 4.3.4).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterruptType {
+    Int,
+    Nmi,
+    Smi,
+    ExtInt,
+    Unknown(u8),
+}
+
+impl InterruptType {
+    pub fn from_u8(num: u8) -> Self {
+        match num {
+            0 => InterruptType::Int,
+            1 => InterruptType::Nmi,
+            2 => InterruptType::Smi,
+            3 => InterruptType::ExtInt,
+            other => InterruptType::Unknown(other),
+        }
+    }
+}
+
+/// The signal polarity encoded in bits 0-1 of the interrupt flags word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Polarity {
+    ConformsToBus,
+    ActiveHigh,
+    ActiveLow,
+    Reserved,
+}
+
+impl Polarity {
+    fn from_flags(flags: u16) -> Self {
+        match flags & 0b11 {
+            0b00 => Polarity::ConformsToBus,
+            0b01 => Polarity::ActiveHigh,
+            0b11 => Polarity::ActiveLow,
+            _ => Polarity::Reserved,
+        }
+    }
+}
+
+/// The trigger mode encoded in bits 2-3 of the interrupt flags word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerMode {
+    ConformsToBus,
+    Edge,
+    Level,
+    Reserved,
+}
+
+impl TriggerMode {
+    fn from_flags(flags: u16) -> Self {
+        match (flags >> 2) & 0b11 {
+            0b00 => TriggerMode::ConformsToBus,
+            0b01 => TriggerMode::Edge,
+            0b11 => TriggerMode::Level,
+            _ => TriggerMode::Reserved,
+        }
+    }
+}
+
+/// A single resolved route: where a bus interrupt lands on an IOAPIC, and how
+/// it should be programmed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InterruptRoute {
+    pub dest_ioapic_id: u8,
+    pub dest_ioapic_int: u8,
+    pub interrupt_type: InterruptType,
+    pub polarity: Polarity,
+    pub trigger_mode: TriggerMode,
+}
+
+/// A queryable view of the I/O interrupt-assignment entries.
+///
+/// It borrows the configuration table and resolves a `(source_bus_id,
+/// source_bus_irq)` pair to the IOAPIC destination that services it, which is
+/// what a consumer needs to program an IOAPIC redirection entry.
+pub struct InterruptRoutingTable<'a> {
+    header: &'a MPConfigurationTableHeader,
+    table: &'a [u8],
+}
+
+impl<'a> InterruptRoutingTable<'a> {
+    /// Resolve the route for a bus interrupt source, if one is assigned.
+    ///
+    /// The scan terminates at the first malformed entry, so any assignments
+    /// before a truncated or corrupt tail are still resolved while the search
+    /// is guaranteed to return.
+    pub fn lookup(&self, source_bus_id: u8, source_bus_irq: u8) -> Option<InterruptRoute> {
+        self.iter()
+            .find(|(id, irq, _)| *id == source_bus_id && *irq == source_bus_irq)
+            .map(|(_, _, route)| route)
+    }
+
+    /// Iterate every `(source_bus_id, source_bus_irq, route)` assignment.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u8, InterruptRoute)> + 'a {
+        self.header
+            .iter(self.table)
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.get_io_interrupt_assignment_entry())
+            .map(|e| {
+                (
+                    e.source_bus_id,
+                    e.source_bus_irq,
+                    InterruptRoute {
+                        dest_ioapic_id: e.dest_ioapic_id,
+                        dest_ioapic_int: e.dest_ioapic_int,
+                        interrupt_type: e.interrupt_type(),
+                        polarity: e.polarity(),
+                        trigger_mode: e.trigger_mode(),
+                    },
+                )
+            })
+    }
+}
+
+impl MPConfigurationTableHeader {
+    /// Build a queryable IOAPIC interrupt routing table over `table`.
+    ///
+    /// `table` must be the configuration table starting at the header, the
+    /// same slice accepted by [`iter`](Self::iter).
+    pub fn interrupt_routing<'a>(&'a self, table: &'a [u8]) -> InterruptRoutingTable<'a> {
+        InterruptRoutingTable { header: self, table }
+    }
+}
+
+/// Human-readable renderers for a decoded MP table.
+///
+/// These only compile with the `std` feature enabled; the core crate stays
+/// `#![no_std]` so kernel consumers do not pay for the diagnostic dump.
+#[cfg(feature = "std")]
+mod display {
+    use super::*;
+    use core::fmt::{self, Display, Formatter};
+
+    /// Render a fixed-size, possibly space-padded ASCII field as a string.
+    fn render_ascii(f: &mut Formatter, bytes: &[u8]) -> fmt::Result {
+        for &b in bytes {
+            if b == 0 {
+                break
+            }
+            write!(f, "{}", b as char)?;
+        }
+        Ok(())
+    }
+
+    impl Display for MPFloatingPointer {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let address = self.physical_address_pointer;
+            write!(f, "MP Floating Pointer (spec rev 1.{}): ", self.spec_rev)?;
+            match self.configuration() {
+                MPConfiguration::TablePresent =>
+                    write!(f, "table at {:#010x}", address)?,
+                MPConfiguration::Default(cfg) =>
+                    write!(f, "default configuration #{} ({:?})", cfg.number, cfg.buses)?,
+                MPConfiguration::Reserved(n) =>
+                    write!(f, "reserved default configuration #{}", n)?,
+            }
+            write!(f, ", IMCR {}", if self.imcr_present() { "present" } else { "absent" })
+        }
+    }
+
+    impl Display for MPConfigurationTableHeader {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "MP Configuration Table (OEM \"")?;
+            render_ascii(f, &self.oem_id)?;
+            write!(f, "\", product \"")?;
+            render_ascii(f, &self.product_id)?;
+            let local_apic_addr = self.local_apic_addr;
+            let entry_count = self.entry_count;
+            write!(f, "\"): {} entries, local APIC at {:#010x}", entry_count, local_apic_addr)
+        }
+    }
+
+    impl Display for ProcessorEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let feature_flags = self.feature_flags;
+            write!(f, "Processor: LAPIC id {} version {}, flags {:#04x}, features {:#010x}",
+                self.lapic_id, self.lapic_version, self.cpu_flags, feature_flags)
+        }
+    }
+
+    impl Display for BusEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "Bus {}: {}", self.bus_id, self.bus_type().as_str())
+        }
+    }
+
+    impl Display for IOAPICEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let ioapic_address = self.ioapic_address;
+            write!(f, "IOAPIC id {} version {}, flags {:#04x}, at {:#010x}",
+                self.ioapic_id, self.ioapic_version, self.ioapic_flags, ioapic_address)
+        }
+    }
+
+    impl Display for IOInterruptAssignmentEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "I/O interrupt {:?} ({:?}, {:?}): bus {} irq {} -> IOAPIC {} INTIN {}",
+                self.interrupt_type(), self.polarity(), self.trigger_mode(),
+                self.source_bus_id, self.source_bus_irq,
+                self.dest_ioapic_id, self.dest_ioapic_int)
+        }
+    }
+
+    impl Display for LocalInterruptAssignmentEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "Local interrupt {:?} ({:?}, {:?}): bus {} irq {} -> LAPIC {} LINTIN {}",
+                self.interrupt_type(), self.polarity(), self.trigger_mode(),
+                self.source_bus_id, self.source_bus_irq,
+                self.dest_ioapic_id, self.dest_ioapic_int)
+        }
+    }
+
+    impl Display for MPEntry {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            match self.code {
+                MPEntryCode::Processor =>
+                    self.get_processor_entry().unwrap().fmt(f),
+                MPEntryCode::Bus =>
+                    self.get_bus_entry().unwrap().fmt(f),
+                MPEntryCode::IOAPIC =>
+                    self.get_ioapic_entry().unwrap().fmt(f),
+                MPEntryCode::IOInterruptAssignment =>
+                    self.get_io_interrupt_assignment_entry().unwrap().fmt(f),
+                MPEntryCode::LocalInterruptAssignment =>
+                    self.get_local_interrupt_assignment_entry().unwrap().fmt(f),
+                MPEntryCode::Unknown => write!(f, "Unknown entry"),
+            }
+        }
+    }
+}